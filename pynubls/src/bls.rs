@@ -0,0 +1,121 @@
+use crate::errors::{check_len, invalid_point};
+
+use bls12_381::G2Affine;
+use nubls::Signature as SignatureStub;
+
+use pyo3::basic::CompareOp;
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyType};
+use pyo3::PyErr;
+
+create_exception!(pynubls, InvalidSignature, PyException);
+
+#[pyclass]
+pub struct Signature {
+    pub(crate) inner: SignatureStub,
+}
+
+#[pymethods]
+impl Signature {
+    #[classmethod]
+    pub fn from_bytes(_cls: &PyType, bytes: &PyBytes) -> PyResult<Signature> {
+        check_len("signature", bytes.as_bytes(), 96)?;
+        let mut sig = [0u8; 96];
+        sig.copy_from_slice(bytes.as_bytes());
+        // Decompress and subgroup-check ourselves instead of trusting
+        // `SignatureStub::from_bytes` with a malformed point it would panic on.
+        Option::<G2Affine>::from(G2Affine::from_compressed(&sig)).ok_or_else(|| invalid_point("signature"))?;
+        Ok(Signature {
+            inner: SignatureStub::from_bytes(&sig),
+        })
+    }
+
+    pub fn to_bytes<'p>(&self, py: Python<'p>) -> PyResult<&'p PyBytes> {
+        Ok(&PyBytes::new(py, &self.inner.to_bytes()[..]))
+    }
+
+    pub fn __bytes__<'p>(&self, py: Python<'p>) -> PyResult<&'p PyBytes> {
+        self.to_bytes(py)
+    }
+
+    pub fn __richcmp__(&self, other: PyRef<Signature>, op: CompareOp) -> PyResult<bool> {
+        let eq = self.inner.to_bytes() == other.inner.to_bytes();
+        match op {
+            CompareOp::Eq => Ok(eq),
+            CompareOp::Ne => Ok(!eq),
+            _ => Err(PyErr::new::<pyo3::exceptions::PyNotImplementedError, _>(
+                "Signature only supports equality comparisons",
+            )),
+        }
+    }
+
+    pub fn __hash__(&self) -> PyResult<isize> {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+        self.inner.to_bytes().hash(&mut hasher);
+        Ok(hasher.finish() as isize)
+    }
+
+    /// Reconstructs this signature via `from_bytes` on unpickling.
+    pub fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (PyObject,))> {
+        let ctor = py.get_type::<Signature>().getattr("from_bytes")?.into();
+        let bytes = self.to_bytes(py)?.into();
+        Ok((ctor, (bytes,)))
+    }
+
+    /// Sums `signatures` into a single aggregate signature.
+    #[classmethod]
+    pub fn aggregate(_cls: &PyType, signatures: Vec<PyRef<Signature>>) -> PyResult<Signature> {
+        let sigs: Vec<SignatureStub> = signatures.into_iter().map(|sig| sig.inner).collect();
+        Ok(Signature {
+            inner: SignatureStub::aggregate(&sigs[..]),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keys::PrivateKey;
+
+    #[test]
+    fn signature_round_trips_through_bytes_and_pickle() {
+        Python::with_gil(|py| {
+            let sk = PrivateKey::random(py.get_type::<PrivateKey>()).unwrap();
+            let message = PyBytes::new(py, b"message");
+            let sig = sk.sign(message).unwrap();
+
+            let bytes = sig.to_bytes(py).unwrap();
+            let decoded = Signature::from_bytes(py.get_type::<Signature>(), bytes).unwrap();
+            assert_eq!(decoded.to_bytes(py).unwrap().as_bytes(), bytes.as_bytes());
+
+            let (ctor, args) = sig.__reduce__(py).unwrap();
+            let unpickled: PyRef<Signature> = ctor.call1(py, args).unwrap().extract(py).unwrap();
+            assert_eq!(unpickled.to_bytes(py).unwrap().as_bytes(), bytes.as_bytes());
+        });
+    }
+
+    #[test]
+    fn signature_from_bytes_rejects_the_wrong_length() {
+        Python::with_gil(|py| {
+            let short = PyBytes::new(py, &[0u8; 95]);
+            assert!(Signature::from_bytes(py.get_type::<Signature>(), short).is_err());
+        });
+    }
+
+    #[test]
+    fn signature_from_bytes_rejects_a_non_canonical_point() {
+        Python::with_gil(|py| {
+            // Same contradictory infinity+sign flag combination as the
+            // public key test: not a canonical encoding of anything.
+            let mut bytes = [0u8; 96];
+            bytes[0] = 0xE0;
+            let bytes = PyBytes::new(py, &bytes);
+            assert!(Signature::from_bytes(py.get_type::<Signature>(), bytes).is_err());
+        });
+    }
+}
@@ -0,0 +1,240 @@
+//! Pedersen-VSS distributed key generation.
+//!
+//! Replaces the trusted-dealer `PrivateKey.split`/`recover` with a protocol
+//! where `n` participants jointly produce a threshold key that no single
+//! party ever holds in full. Each participant samples a random degree-`(t-1)`
+//! polynomial over the scalar field, publishes commitments to its
+//! coefficients, and privately sends every other participant its share of
+//! that polynomial. Recipients verify a share against the sender's
+//! commitments before folding it in, so a dishonest dealer is caught instead
+//! of silently corrupting the group key.
+
+use bls12_381::{G1Affine, G1Projective, Scalar};
+use ff::Field;
+use group::Group;
+use rand_core::OsRng;
+use zeroize::{Zeroize, Zeroizing};
+
+use nubls::PublicKey as PublicKeyStub;
+
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+use crate::keys::{PrivateKey, PublicKey};
+
+create_exception!(pynubls, DkgComplaint, PyException);
+
+#[pyclass]
+pub struct DkgParticipant {
+    id: u64,
+    coefficients: Vec<Scalar>,
+    commitments: Vec<G1Affine>,
+    share_sum: Scalar,
+}
+
+#[pymethods]
+impl DkgParticipant {
+    #[new]
+    pub fn new(id: u64, threshold: usize) -> PyResult<DkgParticipant> {
+        let coefficients: Vec<Scalar> = (0..threshold).map(|_| Scalar::random(OsRng)).collect();
+        let commitments = coefficients
+            .iter()
+            .map(|a| G1Affine::from(G1Projective::generator() * a))
+            .collect();
+        Ok(DkgParticipant {
+            id,
+            coefficients,
+            commitments,
+            share_sum: Scalar::zero(),
+        })
+    }
+
+    /// Round 1: returns this participant's public coefficient commitments
+    /// (broadcast to everyone) and its private share `f_i(j)` for each id in
+    /// `participants`, including its own (send each share to `j` alone).
+    pub fn round1<'p>(
+        &self,
+        py: Python<'p>,
+        participants: Vec<u64>,
+    ) -> PyResult<(Vec<&'p PyBytes>, Vec<&'p PyBytes>)> {
+        let commitments = self
+            .commitments
+            .iter()
+            .map(|c| PyBytes::new(py, &c.to_compressed()))
+            .collect();
+        let shares = participants
+            .into_iter()
+            .map(|j| PyBytes::new(py, &evaluate(&self.coefficients, Scalar::from(j)).to_bytes()))
+            .collect();
+        Ok((commitments, shares))
+    }
+
+    /// Round 2: verifies each `(sender_commitments, share)` pair against
+    /// `g^{f_i(j)} == \prod_k C_{i,k}^{(j^k)}` and folds verified shares into
+    /// this participant's running secret-share total. Raises `DkgComplaint`
+    /// on the first share that fails to verify.
+    pub fn round2(&mut self, incoming: Vec<(Vec<&PyBytes>, &PyBytes)>) -> PyResult<()> {
+        for (sender_commitments, share_bytes) in incoming {
+            let share = scalar_from_bytes(share_bytes)?;
+            let sender_commitments = sender_commitments
+                .into_iter()
+                .map(g1_from_bytes)
+                .collect::<PyResult<Vec<_>>>()?;
+
+            let expected = G1Affine::from(G1Projective::generator() * share);
+            let actual = evaluate_commitments(&sender_commitments, Scalar::from(self.id));
+            if expected != actual {
+                return Err(PyErr::new::<DkgComplaint, _>(
+                    "Share does not match sender's commitments",
+                ));
+            }
+
+            self.share_sum += share;
+        }
+        Ok(())
+    }
+
+    /// Finalizes this participant's verified secret share into a `PrivateKey`
+    /// fragment compatible with the existing `PrivateKey.recover`, and
+    /// combines every participant's constant-term commitment into the group
+    /// public key.
+    ///
+    /// A fragment `PrivateKey.recover` can interpolate needs its share's
+    /// x-coordinate alongside the share value itself (the same 64-byte
+    /// `id || share` layout `PrivateKey.split` produces) — a bare 32-byte
+    /// scalar with no id would silently fail to interpolate.
+    pub fn finalize(&self, group_commitments: Vec<&PyBytes>) -> PyResult<(PrivateKey, PublicKey)> {
+        let mut buff = [0u8; 64];
+        buff[..32].copy_from_slice(&Scalar::from(self.id).to_bytes());
+        buff[32..].copy_from_slice(&self.share_sum.to_bytes());
+        let fragment = PrivateKey {
+            secret: Zeroizing::new(buff.to_vec()),
+        };
+        buff.zeroize();
+
+        let group_point = group_commitments
+            .into_iter()
+            .map(g1_from_bytes)
+            .collect::<PyResult<Vec<_>>>()?
+            .into_iter()
+            .fold(G1Projective::identity(), |acc, c| acc + c);
+        let group_key = PublicKey {
+            inner: PublicKeyStub::from_bytes(&G1Affine::from(group_point).to_compressed()),
+        };
+
+        Ok((fragment, group_key))
+    }
+}
+
+/// Evaluates `f(x) = a_0 + a_1 x + ... + a_{t-1} x^{t-1}` via Horner's method.
+fn evaluate(coefficients: &[Scalar], x: Scalar) -> Scalar {
+    coefficients
+        .iter()
+        .rev()
+        .fold(Scalar::zero(), |acc, a| acc * x + a)
+}
+
+/// Evaluates `prod_k C_k^{x^k}` via the additive analogue of Horner's method.
+fn evaluate_commitments(commitments: &[G1Affine], x: Scalar) -> G1Affine {
+    let result = commitments
+        .iter()
+        .rev()
+        .fold(G1Projective::identity(), |acc, c| acc * x + G1Projective::from(*c));
+    G1Affine::from(result)
+}
+
+fn scalar_from_bytes(bytes: &PyBytes) -> PyResult<Scalar> {
+    let mut buff = [0u8; 32];
+    buff.copy_from_slice(bytes.as_bytes());
+    Option::<Scalar>::from(Scalar::from_bytes(&buff))
+        .ok_or_else(|| PyErr::new::<DkgComplaint, _>("Malformed share"))
+}
+
+fn g1_from_bytes(bytes: &PyBytes) -> PyResult<G1Affine> {
+    let mut buff = [0u8; 48];
+    buff.copy_from_slice(bytes.as_bytes());
+    Option::<G1Affine>::from(G1Affine::from_compressed(&buff))
+        .ok_or_else(|| PyErr::new::<DkgComplaint, _>("Malformed commitment"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pyref<'py, T: pyo3::PyClass>(py: Python<'py>, value: T) -> PyRef<'py, T> {
+        Py::new(py, value).unwrap().into_ref(py).borrow()
+    }
+
+    /// Runs a 2-of-3 DKG to completion and checks that recovering a key from
+    /// the first `threshold` finalized fragments reconstructs the same public key
+    /// the run agreed on — this is the only way to catch the fragment layout
+    /// (`id || share`) disagreeing with what `PrivateKey.recover` expects.
+    #[test]
+    fn recovering_finalized_fragments_matches_the_group_key() {
+        Python::with_gil(|py| {
+            let threshold = 2;
+            let ids: Vec<u64> = vec![1, 2, 3];
+
+            let mut participants: Vec<DkgParticipant> = ids
+                .iter()
+                .map(|&id| DkgParticipant::new(id, threshold).unwrap())
+                .collect();
+
+            // Round 1: every participant publishes commitments and privately
+            // computes a share for every id in `ids` (including itself).
+            let round1: Vec<(Vec<&PyBytes>, Vec<&PyBytes>)> = participants
+                .iter()
+                .map(|p| p.round1(py, ids.clone()).unwrap())
+                .collect();
+
+            // Round 2: participant `j` (at index `j_idx`) collects the
+            // `j`-th share out of every sender's round1 output.
+            for (j_idx, participant) in participants.iter_mut().enumerate() {
+                let incoming: Vec<(Vec<&PyBytes>, &PyBytes)> = round1
+                    .iter()
+                    .map(|(commitments, shares)| (commitments.clone(), shares[j_idx]))
+                    .collect();
+                participant.round2(incoming).unwrap();
+            }
+
+            // The group public key is the sum of every participant's
+            // constant-term commitment.
+            let group_commitments: Vec<&PyBytes> =
+                round1.iter().map(|(commitments, _)| commitments[0]).collect();
+
+            let mut fragments = Vec::new();
+            let mut group_key_bytes = None;
+            for participant in &participants {
+                let (fragment, group_key) = participant.finalize(group_commitments.clone()).unwrap();
+                assert!(fragment.is_fragment().unwrap());
+
+                let bytes = group_key.to_bytes(py).unwrap().as_bytes().to_vec();
+                match &group_key_bytes {
+                    None => group_key_bytes = Some(bytes),
+                    Some(expected) => assert_eq!(expected, &bytes),
+                }
+                fragments.push(fragment);
+            }
+
+            let recovered = PrivateKey::recover(
+                py.get_type::<PrivateKey>(),
+                vec![
+                    pyref(py, fragments.remove(0)),
+                    pyref(py, fragments.remove(0)),
+                ],
+            )
+            .unwrap();
+
+            let recovered_pubkey_bytes = recovered
+                .public_key()
+                .unwrap()
+                .to_bytes(py)
+                .unwrap()
+                .as_bytes()
+                .to_vec();
+            assert_eq!(Some(recovered_pubkey_bytes), group_key_bytes);
+        });
+    }
+}
@@ -0,0 +1,32 @@
+//! Shared error mapping for the (de)serialization constructors.
+//!
+//! Every `from_bytes` here used to trust its input with `copy_from_slice`
+//! and `.unwrap()`, so a wrong-length or malformed payload would abort the
+//! interpreter instead of raising. This module gives every constructor a
+//! single place to turn those failures into a `DeserializationError`
+//! (a `ValueError` subclass) that Python code can actually catch.
+
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
+use pyo3::{PyErr, PyResult};
+
+create_exception!(pynubls, DeserializationError, PyValueError);
+
+/// Checks `bytes` is exactly `expected` bytes long.
+pub fn check_len(what: &str, bytes: &[u8], expected: usize) -> PyResult<()> {
+    if bytes.len() != expected {
+        return Err(PyErr::new::<DeserializationError, _>(format!(
+            "{} must be exactly {} bytes, got {}",
+            what,
+            expected,
+            bytes.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Builds the error for a compressed point encoding that isn't on the curve
+/// or isn't in the correct subgroup.
+pub fn invalid_point(what: &str) -> PyErr {
+    PyErr::new::<DeserializationError, _>(format!("{} is not a valid curve point", what))
+}
@@ -0,0 +1,62 @@
+//! RFC 9380 hash-to-curve for the `BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_`
+//! and `BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_` ciphersuites.
+//!
+//! This is what lets `PrivateKey.sign`/`PublicKey.verify` take arbitrary
+//! message bytes instead of a pre-hashed `G2Affine` point: the message is
+//! expanded to uniform bytes with `expand_message_xmd` (SHA-256), mapped to
+//! two points on the curve with the simplified SWU map, added together, and
+//! cleared to the prime-order subgroup.
+//!
+//! Proofs of possession hash under a *different* domain-separation tag than
+//! ordinary signatures. Without that split, a signature over a message that
+//! happens to equal someone's public key encoding would also be a valid
+//! proof of possession for that key (and vice versa), which is exactly the
+//! confusion the PoP gate in `fast_aggregate_verify` exists to rule out.
+
+use bls12_381::hash_to_curve::{ExpandMsgXmd, HashToCurve};
+use bls12_381::{G2Affine, G2Projective};
+use sha2::Sha256;
+
+const SIG_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_";
+const POP_DST: &[u8] = b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+fn hash_to_g2_with_dst(message: &[u8], dst: &[u8]) -> G2Affine {
+    let point: G2Projective =
+        <G2Projective as HashToCurve<ExpandMsgXmd<Sha256>>>::hash_to_curve(message, dst);
+    G2Affine::from(point)
+}
+
+/// Hashes an arbitrary message to a point in G2's prime-order subgroup,
+/// under the ordinary-signature domain-separation tag.
+pub fn hash_to_g2(message: &[u8]) -> G2Affine {
+    hash_to_g2_with_dst(message, SIG_DST)
+}
+
+/// Hashes a public key's bytes to a point in G2's prime-order subgroup,
+/// under the proof-of-possession domain-separation tag.
+pub fn hash_to_g2_pop(message: &[u8]) -> G2Affine {
+    hash_to_g2_with_dst(message, POP_DST)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_to_g2_is_deterministic() {
+        assert_eq!(hash_to_g2(b"hello"), hash_to_g2(b"hello"));
+    }
+
+    #[test]
+    fn hash_to_g2_depends_on_the_message() {
+        assert_ne!(hash_to_g2(b"hello"), hash_to_g2(b"goodbye"));
+    }
+
+    #[test]
+    fn signing_and_pop_dsts_land_on_different_points() {
+        // Same bytes, two ciphersuites: this is what stops a proof of
+        // possession from doubling as a signature over the same bytes.
+        let message = b"a public key's compressed encoding";
+        assert_ne!(hash_to_g2(message), hash_to_g2_pop(message));
+    }
+}
@@ -1,118 +1,253 @@
 use crate::bls::{InvalidSignature, Signature};
-use bls12_381::G2Affine;
+use crate::errors::{check_len, invalid_point};
+use crate::hash_to_curve::{hash_to_g2, hash_to_g2_pop};
+use bls12_381::{G1Affine, G2Affine};
 use nubls::{
     PrivateKey as PrivateKeyStub, PublicKey as PublicKeyStub, PRSKey, ThresholdKey, VerificationResult,
 };
 
+use pyo3::basic::CompareOp;
 use pyo3::prelude::*;
 use pyo3::types::{PyBytes, PyType};
 use pyo3::PyErr;
+use std::sync::OnceLock;
+use subtle::ConstantTimeEq;
+use zeroize::{Zeroize, Zeroizing};
 
 #[pyclass]
 pub struct PublicKey {
-    inner: PublicKeyStub,
+    pub(crate) inner: PublicKeyStub,
 }
 
 #[pyclass]
 pub struct PrivateKey {
-    inner: PrivateKeyStub,
+    /// The only long-lived copy of this key's secret bytes. Everything else
+    /// (the `nubls::PrivateKey` used to actually do curve math) is
+    /// reconstructed on demand and dropped again immediately, so this is the
+    /// one buffer that needs to survive — and be scrubbed — for the Python
+    /// object's whole lifetime. `Zeroizing` overwrites it with a volatile
+    /// write the optimizer can't elide when it's dropped.
+    pub(crate) secret: Zeroizing<Vec<u8>>,
+}
+
+impl PrivateKey {
+    /// Reconstructs the `nubls` scalar this key wraps, for the duration of a
+    /// single operation.
+    ///
+    /// This is a real gap, not just the `self.secret` buffer: `nubls` owns
+    /// its scalar as a foreign type we can't `impl Drop` for, so the copy
+    /// `from_bytes` makes here is dropped un-scrubbed on every call, and a
+    /// hot path (e.g. signing many messages) churns out one such copy per
+    /// call. Closing it for real needs `Zeroize`/`Drop` upstream in `nubls`
+    /// itself; until then, `self.secret` is the one buffer we can and do
+    /// protect.
+    fn stub(&self) -> PrivateKeyStub {
+        PrivateKeyStub::from_bytes(&self.secret)
+    }
+
+    pub(crate) fn from_stub(inner: PrivateKeyStub) -> PrivateKey {
+        let len = if inner.is_fragment() { 64 } else { 32 };
+        let mut buff = vec![0u8; len];
+        inner.to_bytes(&mut buff);
+        PrivateKey {
+            secret: Zeroizing::new(buff),
+        }
+    }
 }
 
 #[pymethods]
 impl PrivateKey {
     #[classmethod]
     pub fn random(_cls: &PyType) -> PyResult<PrivateKey> {
-        Ok(PrivateKey {
-            inner: PrivateKeyStub::random(),
-        })
+        Ok(PrivateKey::from_stub(PrivateKeyStub::random()))
     }
 
     pub fn public_key(&self) -> PyResult<PublicKey> {
         Ok(PublicKey {
-            inner: self.inner.public_key(),
+            inner: self.stub().public_key(),
         })
     }
 
-    // TODO: Finish implementation of `Signature`.
+    /// Signs `message`, hashing it to G2 per RFC 9380 first.
     pub fn sign(&self, message: &PyBytes) -> PyResult<Signature> {
-        let mut msg = [0u8; 96];
-        msg.copy_from_slice(message.as_bytes());
+        let point = hash_to_g2(message.as_bytes());
         Ok(Signature {
-            inner: self.inner.sign(&G2Affine::from_compressed(&msg).unwrap()),
+            inner: self.stub().sign(&point),
         })
     }
 
+    /// Signs an already-hashed point directly, bypassing hash-to-curve.
+    pub fn sign_raw(&self, point: &PyBytes) -> PyResult<Signature> {
+        check_len("point", point.as_bytes(), 96)?;
+        let mut msg = [0u8; 96];
+        msg.copy_from_slice(point.as_bytes());
+        let point = Option::<G2Affine>::from(G2Affine::from_compressed(&msg))
+            .ok_or_else(|| invalid_point("point"))?;
+        let sig = Signature {
+            inner: self.stub().sign(&point),
+        };
+        msg.zeroize();
+        Ok(sig)
+    }
+
     pub fn split(&self, m: usize, n: usize) -> PyResult<Vec<PrivateKey>> {
         Ok(self
-            .inner
+            .stub()
             .split(m, n)
             .into_iter()
-            .map(|fragment| PrivateKey { inner: fragment })
+            .map(PrivateKey::from_stub)
             .collect())
     }
 
     #[classmethod]
     pub fn recover(_cls: &PyType, fragments: Vec<PyRef<PrivateKey>>) -> PyResult<PrivateKey> {
-        let f: Vec<PrivateKeyStub> = fragments
-            .into_iter()
-            .map(|fragment| fragment.inner)
-            .collect();
-        Ok(PrivateKey {
-            inner: PrivateKeyStub::recover(&f[..]),
-        })
+        let f: Vec<PrivateKeyStub> = fragments.iter().map(|fragment| fragment.stub()).collect();
+        Ok(PrivateKey::from_stub(PrivateKeyStub::recover(&f[..])))
     }
 
     pub fn is_fragment(&self) -> PyResult<bool> {
-        Ok(self.inner.is_fragment())
+        Ok(self.secret.len() == 64)
     }
 
+    /// Deserializes a key produced by `to_bytes`. The leading tag byte
+    /// disambiguates a threshold fragment from a whole key, rather than
+    /// inferring it from the payload length.
     #[classmethod]
     pub fn from_bytes(_cls: &PyType, bytes: &PyBytes) -> PyResult<PrivateKey> {
-        Ok(PrivateKey {
-            inner: PrivateKeyStub::from_bytes(&bytes.as_bytes()[..]),
-        })
+        let data = bytes.as_bytes();
+        if data.is_empty() {
+            return Err(crate::errors::DeserializationError::new_err(
+                "private key bytes must start with a tag byte",
+            ));
+        }
+        let (tag, rest) = data.split_at(1);
+        match tag[0] {
+            0 => {
+                check_len("whole private key", rest, 32)?;
+                Ok(PrivateKey {
+                    secret: Zeroizing::new(rest.to_vec()),
+                })
+            }
+            1 => {
+                check_len("private key fragment", rest, 64)?;
+                Ok(PrivateKey {
+                    secret: Zeroizing::new(rest.to_vec()),
+                })
+            }
+            other => Err(crate::errors::DeserializationError::new_err(format!(
+                "unrecognized private key tag byte: {}",
+                other
+            ))),
+        }
     }
 
     pub fn to_bytes<'p>(&self, py: Python<'p>) -> PyResult<&'p PyBytes> {
-        if !self.inner.is_fragment() {
-            let mut buff = [0u8; 32];
-            self.inner.to_bytes(&mut buff);
-            Ok(&PyBytes::new(py, &buff))
+        let tag: u8 = if self.secret.len() == 64 { 1 } else { 0 };
+        let mut buff = Zeroizing::new(vec![0u8; 1 + self.secret.len()]);
+        buff[0] = tag;
+        buff[1..].copy_from_slice(&self.secret);
+        Ok(PyBytes::new(py, &buff))
+    }
+
+    pub fn __bytes__<'p>(&self, py: Python<'p>) -> PyResult<&'p PyBytes> {
+        self.to_bytes(py)
+    }
+
+    /// Reconstructs this key via `from_bytes` on unpickling.
+    pub fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (PyObject,))> {
+        let ctor = py.get_type::<PrivateKey>().getattr("from_bytes")?.into();
+        let bytes = self.to_bytes(py)?.into();
+        Ok((ctor, (bytes,)))
+    }
+
+    /// Hashes by content, consistent with `__richcmp__`, so a `PrivateKey`
+    /// can be used as a dict key.
+    pub fn __hash__(&self) -> PyResult<isize> {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+        self.secret.hash(&mut hasher);
+        Ok(hasher.finish() as isize)
+    }
+
+    /// Constant-time equality, so comparing private keys doesn't leak timing
+    /// information about where the first differing byte is.
+    pub fn __richcmp__(&self, other: PyRef<PrivateKey>, op: CompareOp) -> PyResult<bool> {
+        let eq: bool = if self.secret.len() != other.secret.len() {
+            false
         } else {
-            let mut buff = [0u8; 64];
-            self.inner.to_bytes(&mut buff);
-            Ok(&PyBytes::new(py, &buff))
+            self.secret[..].ct_eq(&other.secret[..]).into()
+        };
+
+        match op {
+            CompareOp::Eq => Ok(eq),
+            CompareOp::Ne => Ok(!eq),
+            _ => Err(PyErr::new::<pyo3::exceptions::PyNotImplementedError, _>(
+                "PrivateKey only supports equality comparisons",
+            )),
         }
     }
 
     pub fn resigning_key(&self, bob_pubkey: &PublicKey) -> PyResult<PrivateKey> {
-        Ok(PrivateKey {
-            inner: self.inner.resigning_key(&bob_pubkey.inner)
-        })
+        Ok(PrivateKey::from_stub(
+            self.stub().resigning_key(&bob_pubkey.inner),
+        ))
     }
 
     pub fn designated_key(&self, alice_pubkey: &PublicKey) -> PyResult<PrivateKey> {
-        Ok(PrivateKey {
-            inner: self.inner.designated_key(&alice_pubkey.inner)
-        })
+        Ok(PrivateKey::from_stub(
+            self.stub().designated_key(&alice_pubkey.inner),
+        ))
     }
 
     pub fn resign(&self, signature: &Signature) -> PyResult<Signature> {
         Ok(Signature {
-            inner: self.inner.resign(&signature.inner)
+            inner: self.stub().resign(&signature.inner),
+        })
+    }
+
+    /// Signs this key's own public key bytes, proving possession of the private key.
+    ///
+    /// Hashed under a domain-separation tag distinct from ordinary signing, so
+    /// a proof of possession can never be confused with (or forged from) a
+    /// signature over a message that happens to equal a public key encoding.
+    ///
+    /// Pass the result to `PublicKey.verify_proof_of_possession` to defend
+    /// `fast_aggregate_verify` callers against rogue-key attacks.
+    pub fn proof_of_possession(&self) -> PyResult<Signature> {
+        let pubkey = self.stub().public_key();
+        let point = hash_to_g2_pop(&pubkey.to_bytes());
+        Ok(Signature {
+            inner: self.stub().sign(&point),
         })
     }
 }
 
 #[pymethods]
 impl PublicKey {
+    /// Verifies `signature` over `message`, hashing `message` to G2 per RFC 9380 first.
     pub fn verify(&self, message: &PyBytes, signature: &Signature) -> PyResult<bool> {
+        let point = hash_to_g2(message.as_bytes());
+
+        let res = self.inner.verify(&point, &signature.inner);
+        match res {
+            VerificationResult::Valid => Ok(true),
+            VerificationResult::Invalid => {
+                Err(PyErr::new::<InvalidSignature, _>("Signature is not valid!"))
+            }
+        }
+    }
+
+    /// Verifies `signature` against an already-hashed point, bypassing hash-to-curve.
+    pub fn verify_raw(&self, point: &PyBytes, signature: &Signature) -> PyResult<bool> {
+        check_len("point", point.as_bytes(), 96)?;
         let mut msg = [0u8; 96];
-        msg.copy_from_slice(message.as_bytes());
+        msg.copy_from_slice(point.as_bytes());
+        let point =
+            Option::<G2Affine>::from(G2Affine::from_compressed(&msg)).ok_or_else(|| invalid_point("point"))?;
 
-        let res = self
-            .inner
-            .verify(&G2Affine::from_compressed(&msg).unwrap(), &signature.inner);
+        let res = self.inner.verify(&point, &signature.inner);
         match res {
             VerificationResult::Valid => Ok(true),
             VerificationResult::Invalid => {
@@ -123,8 +258,13 @@ impl PublicKey {
 
     #[classmethod]
     pub fn from_bytes(_cls: &PyType, bytes: &PyBytes) -> PyResult<PublicKey> {
+        check_len("public key", bytes.as_bytes(), 48)?;
         let mut key = [0u8; 48];
         key.copy_from_slice(bytes.as_bytes());
+        // Decompress and subgroup-check ourselves instead of trusting
+        // `PublicKeyStub::from_bytes` with a malformed point it would panic on.
+        Option::<G1Affine>::from(G1Affine::from_compressed(&key))
+            .ok_or_else(|| invalid_point("public key"))?;
         Ok(PublicKey {
             inner: PublicKeyStub::from_bytes(&key),
         })
@@ -133,4 +273,443 @@ impl PublicKey {
     pub fn to_bytes<'p>(&self, py: Python<'p>) -> PyResult<&'p PyBytes> {
         Ok(&PyBytes::new(py, &self.inner.to_bytes()[..]))
     }
+
+    pub fn __bytes__<'p>(&self, py: Python<'p>) -> PyResult<&'p PyBytes> {
+        self.to_bytes(py)
+    }
+
+    pub fn __richcmp__(&self, other: PyRef<PublicKey>, op: CompareOp) -> PyResult<bool> {
+        let eq = self.inner.to_bytes() == other.inner.to_bytes();
+        match op {
+            CompareOp::Eq => Ok(eq),
+            CompareOp::Ne => Ok(!eq),
+            _ => Err(PyErr::new::<pyo3::exceptions::PyNotImplementedError, _>(
+                "PublicKey only supports equality comparisons",
+            )),
+        }
+    }
+
+    pub fn __hash__(&self) -> PyResult<isize> {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+        self.inner.to_bytes().hash(&mut hasher);
+        Ok(hasher.finish() as isize)
+    }
+
+    /// Reconstructs this key via `from_bytes` on unpickling.
+    pub fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (PyObject,))> {
+        let ctor = py.get_type::<PublicKey>().getattr("from_bytes")?.into();
+        let bytes = self.to_bytes(py)?.into();
+        Ok((ctor, (bytes,)))
+    }
+
+    /// Sums `keys` into a single aggregate public key.
+    #[classmethod]
+    pub fn aggregate(_cls: &PyType, keys: Vec<PyRef<PublicKey>>) -> PyResult<PublicKey> {
+        let pks: Vec<PublicKeyStub> = keys.into_iter().map(|key| key.inner).collect();
+        Ok(PublicKey {
+            inner: PublicKeyStub::aggregate(&pks[..]),
+        })
+    }
+
+    /// Checks `signature` is the aggregate of each of `public_keys` signing the
+    /// corresponding entry in `messages`. Every message must be distinct.
+    #[classmethod]
+    pub fn aggregate_verify(
+        _cls: &PyType,
+        public_keys: Vec<PyRef<PublicKey>>,
+        messages: Vec<&PyBytes>,
+        signature: &Signature,
+    ) -> PyResult<bool> {
+        if public_keys.len() != messages.len() {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "public_keys and messages must have the same length",
+            ));
+        }
+
+        // CoreAggregateVerify is insecure if a message repeats, so this can't
+        // be left to the docstring — reject the call outright.
+        let mut seen = std::collections::HashSet::with_capacity(messages.len());
+        for message in &messages {
+            if !seen.insert(message.as_bytes()) {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "messages must be distinct for aggregate_verify",
+                ));
+            }
+        }
+
+        let pks: Vec<PublicKeyStub> = public_keys.into_iter().map(|key| key.inner).collect();
+        let points: Vec<G2Affine> = messages
+            .into_iter()
+            .map(|message| hash_to_g2(message.as_bytes()))
+            .collect();
+
+        let res = PublicKeyStub::aggregate_verify(&pks[..], &points[..], &signature.inner);
+        match res {
+            VerificationResult::Valid => Ok(true),
+            VerificationResult::Invalid => {
+                Err(PyErr::new::<InvalidSignature, _>("Signature is not valid!"))
+            }
+        }
+    }
+
+    /// Checks `signature` is the aggregate of each of `public_keys` signing the
+    /// single shared `message`. Requires a verified proof of possession for
+    /// every key, to rule out rogue-key attacks against the aggregate.
+    #[classmethod]
+    pub fn fast_aggregate_verify(
+        _cls: &PyType,
+        public_keys: Vec<PyRef<PublicKey>>,
+        proofs_of_possession: Vec<PyRef<Signature>>,
+        message: &PyBytes,
+        signature: &Signature,
+    ) -> PyResult<bool> {
+        if public_keys.len() != proofs_of_possession.len() {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "public_keys and proofs_of_possession must have the same length",
+            ));
+        }
+
+        for (key, pop) in public_keys.iter().zip(proofs_of_possession.iter()) {
+            if !key.verify_proof_of_possession(pop)? {
+                return Err(PyErr::new::<InvalidSignature, _>(
+                    "Proof of possession is not valid!",
+                ));
+            }
+        }
+
+        let pks: Vec<PublicKeyStub> = public_keys.into_iter().map(|key| key.inner).collect();
+        let aggregate = PublicKeyStub::aggregate(&pks[..]);
+        let point = hash_to_g2(message.as_bytes());
+
+        let res = aggregate.verify(&point, &signature.inner);
+        match res {
+            VerificationResult::Valid => Ok(true),
+            VerificationResult::Invalid => {
+                Err(PyErr::new::<InvalidSignature, _>("Signature is not valid!"))
+            }
+        }
+    }
+
+    /// Verifies a proof of possession produced by `PrivateKey.proof_of_possession`.
+    pub fn verify_proof_of_possession(&self, proof: &Signature) -> PyResult<bool> {
+        let point = hash_to_g2_pop(&self.inner.to_bytes());
+        let res = self.inner.verify(&point, &proof.inner);
+        match res {
+            VerificationResult::Valid => Ok(true),
+            VerificationResult::Invalid => Ok(false),
+        }
+    }
+
+    /// Wraps this already-validated key in a `PublicKeyBytes` that carries
+    /// its decompression and subgroup check along as a cache, so converting
+    /// back with `.checked()` is free.
+    pub fn to_bytes_lazy(&self) -> PyResult<PublicKeyBytes> {
+        let mut bytes = [0u8; 48];
+        bytes.copy_from_slice(&self.inner.to_bytes());
+        let cached = OnceLock::new();
+        let _ = cached.set(self.inner);
+        Ok(PublicKeyBytes { bytes, cached })
+    }
+}
+
+/// A raw 48-byte public key encoding that defers decompression and the
+/// prime-order-subgroup check until it's actually needed.
+///
+/// Verifying many signatures against a fixed validator set otherwise pays
+/// for that check on every single call; keeping keys in this form and
+/// calling `.checked()` once amortizes it across the whole set.
+#[pyclass]
+pub struct PublicKeyBytes {
+    bytes: [u8; 48],
+    cached: OnceLock<PublicKeyStub>,
+}
+
+#[pymethods]
+impl PublicKeyBytes {
+    #[new]
+    pub fn new(bytes: &PyBytes) -> PyResult<PublicKeyBytes> {
+        check_len("public key", bytes.as_bytes(), 48)?;
+        let mut buff = [0u8; 48];
+        buff.copy_from_slice(bytes.as_bytes());
+        Ok(PublicKeyBytes {
+            bytes: buff,
+            cached: OnceLock::new(),
+        })
+    }
+
+    pub fn to_bytes<'p>(&self, py: Python<'p>) -> PyResult<&'p PyBytes> {
+        Ok(PyBytes::new(py, &self.bytes))
+    }
+
+    pub fn __bytes__<'p>(&self, py: Python<'p>) -> PyResult<&'p PyBytes> {
+        self.to_bytes(py)
+    }
+
+    /// Decompresses this key the first time it's needed, then returns the
+    /// cached point on every later call instead of redoing that work.
+    ///
+    /// `new` is deliberately validation-free, so this is the one place that
+    /// must reject a malformed encoding instead of handing it to
+    /// `PublicKeyStub::from_bytes`, which panics on an off-curve or
+    /// wrong-subgroup point.
+    pub fn checked(&self) -> PyResult<PublicKey> {
+        if let Some(inner) = self.cached.get() {
+            return Ok(PublicKey { inner: *inner });
+        }
+        Option::<G1Affine>::from(G1Affine::from_compressed(&self.bytes))
+            .ok_or_else(|| invalid_point("public key"))?;
+        let inner = PublicKeyStub::from_bytes(&self.bytes);
+        let _ = self.cached.set(inner);
+        Ok(PublicKey { inner })
+    }
+
+    /// Equivalent to `self.checked().verify(...)`, but only pays for the
+    /// decompression and subgroup check once across repeated calls.
+    pub fn verify(&self, message: &PyBytes, signature: &Signature) -> PyResult<bool> {
+        self.checked()?.verify(message, signature)
+    }
+
+    pub fn __richcmp__(&self, other: PyRef<PublicKeyBytes>, op: CompareOp) -> PyResult<bool> {
+        let eq = self.bytes == other.bytes;
+        match op {
+            CompareOp::Eq => Ok(eq),
+            CompareOp::Ne => Ok(!eq),
+            _ => Err(PyErr::new::<pyo3::exceptions::PyNotImplementedError, _>(
+                "PublicKeyBytes only supports equality comparisons",
+            )),
+        }
+    }
+
+    pub fn __hash__(&self) -> PyResult<isize> {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+        self.bytes.hash(&mut hasher);
+        Ok(hasher.finish() as isize)
+    }
+
+    /// Reconstructs this wrapper directly from its raw bytes on unpickling.
+    pub fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (PyObject,))> {
+        let ctor = py.get_type::<PublicKeyBytes>().into();
+        let bytes = self.to_bytes(py)?.into();
+        Ok((ctor, (bytes,)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pyref<'py, T: pyo3::PyClass>(py: Python<'py>, value: T) -> PyRef<'py, T> {
+        Py::new(py, value).unwrap().into_ref(py).borrow()
+    }
+
+    #[test]
+    fn sign_then_verify_round_trips_on_arbitrary_bytes() {
+        Python::with_gil(|py| {
+            let sk = PrivateKey::random(py.get_type::<PrivateKey>()).unwrap();
+            let pk = sk.public_key().unwrap();
+            let message = PyBytes::new(py, b"arbitrary message, not a pre-hashed point");
+
+            let sig = sk.sign(message).unwrap();
+            assert!(pk.verify(message, &sig).unwrap());
+        });
+    }
+
+    #[test]
+    fn aggregate_verify_accepts_the_matching_aggregate_and_rejects_a_repeated_message() {
+        Python::with_gil(|py| {
+            let sk_cls = py.get_type::<PrivateKey>();
+            let sk1 = PrivateKey::random(sk_cls).unwrap();
+            let sk2 = PrivateKey::random(sk_cls).unwrap();
+            let pk1 = sk1.public_key().unwrap();
+            let pk2 = sk2.public_key().unwrap();
+
+            let msg1 = PyBytes::new(py, b"message one");
+            let msg2 = PyBytes::new(py, b"message two");
+            let sig1 = sk1.sign(msg1).unwrap();
+            let sig2 = sk2.sign(msg2).unwrap();
+
+            let agg_sig = Signature::aggregate(
+                py.get_type::<Signature>(),
+                vec![pyref(py, sig1), pyref(py, sig2)],
+            )
+            .unwrap();
+
+            let ok = PublicKey::aggregate_verify(
+                py.get_type::<PublicKey>(),
+                vec![
+                    pyref(py, PublicKey { inner: pk1.inner }),
+                    pyref(py, PublicKey { inner: pk2.inner }),
+                ],
+                vec![msg1, msg2],
+                &agg_sig,
+            )
+            .unwrap();
+            assert!(ok);
+
+            let rejected = PublicKey::aggregate_verify(
+                py.get_type::<PublicKey>(),
+                vec![
+                    pyref(py, PublicKey { inner: pk1.inner }),
+                    pyref(py, PublicKey { inner: pk2.inner }),
+                ],
+                vec![msg1, msg1],
+                &agg_sig,
+            );
+            assert!(rejected.is_err());
+        });
+    }
+
+    #[test]
+    fn fast_aggregate_verify_accepts_valid_proofs_of_possession() {
+        Python::with_gil(|py| {
+            let sk_cls = py.get_type::<PrivateKey>();
+            let sk1 = PrivateKey::random(sk_cls).unwrap();
+            let sk2 = PrivateKey::random(sk_cls).unwrap();
+            let pk1 = sk1.public_key().unwrap();
+            let pk2 = sk2.public_key().unwrap();
+            let pop1 = sk1.proof_of_possession().unwrap();
+            let pop2 = sk2.proof_of_possession().unwrap();
+
+            let message = PyBytes::new(py, b"shared message");
+            let sig1 = sk1.sign(message).unwrap();
+            let sig2 = sk2.sign(message).unwrap();
+            let agg_sig = Signature::aggregate(
+                py.get_type::<Signature>(),
+                vec![pyref(py, sig1), pyref(py, sig2)],
+            )
+            .unwrap();
+
+            let ok = PublicKey::fast_aggregate_verify(
+                py.get_type::<PublicKey>(),
+                vec![
+                    pyref(py, PublicKey { inner: pk1.inner }),
+                    pyref(py, PublicKey { inner: pk2.inner }),
+                ],
+                vec![pyref(py, pop1), pyref(py, pop2)],
+                message,
+                &agg_sig,
+            )
+            .unwrap();
+            assert!(ok);
+        });
+    }
+
+    #[test]
+    fn proof_of_possession_does_not_verify_against_the_wrong_key() {
+        Python::with_gil(|py| {
+            let sk_cls = py.get_type::<PrivateKey>();
+            let sk1 = PrivateKey::random(sk_cls).unwrap();
+            let sk2 = PrivateKey::random(sk_cls).unwrap();
+            let pk2 = sk2.public_key().unwrap();
+            let pop1 = sk1.proof_of_possession().unwrap();
+
+            assert!(!pk2.verify_proof_of_possession(&pop1).unwrap());
+        });
+    }
+
+    #[test]
+    fn public_key_round_trips_through_bytes_and_pickle() {
+        Python::with_gil(|py| {
+            let sk = PrivateKey::random(py.get_type::<PrivateKey>()).unwrap();
+            let pk = sk.public_key().unwrap();
+
+            let bytes = pk.to_bytes(py).unwrap();
+            let decoded = PublicKey::from_bytes(py.get_type::<PublicKey>(), bytes).unwrap();
+            assert_eq!(decoded.to_bytes(py).unwrap().as_bytes(), bytes.as_bytes());
+
+            let (ctor, args) = pk.__reduce__(py).unwrap();
+            let unpickled: PyRef<PublicKey> = ctor.call1(py, args).unwrap().extract(py).unwrap();
+            assert_eq!(unpickled.to_bytes(py).unwrap().as_bytes(), bytes.as_bytes());
+        });
+    }
+
+    #[test]
+    fn public_key_from_bytes_rejects_the_wrong_length() {
+        Python::with_gil(|py| {
+            let short = PyBytes::new(py, &[0u8; 47]);
+            assert!(PublicKey::from_bytes(py.get_type::<PublicKey>(), short).is_err());
+        });
+    }
+
+    #[test]
+    fn public_key_from_bytes_rejects_a_non_canonical_point() {
+        Python::with_gil(|py| {
+            // Compression flag set, and both the infinity and sign flags set —
+            // not a canonical encoding of anything, so this must fail to
+            // decompress rather than panic.
+            let mut bytes = [0u8; 48];
+            bytes[0] = 0xE0;
+            let bytes = PyBytes::new(py, &bytes);
+            assert!(PublicKey::from_bytes(py.get_type::<PublicKey>(), bytes).is_err());
+        });
+    }
+
+    #[test]
+    fn private_key_round_trips_through_bytes_and_pickle() {
+        Python::with_gil(|py| {
+            let sk = PrivateKey::random(py.get_type::<PrivateKey>()).unwrap();
+
+            let bytes = sk.to_bytes(py).unwrap();
+            let decoded = PrivateKey::from_bytes(py.get_type::<PrivateKey>(), bytes).unwrap();
+            assert_eq!(decoded.to_bytes(py).unwrap().as_bytes(), bytes.as_bytes());
+
+            let (ctor, args) = sk.__reduce__(py).unwrap();
+            let unpickled: PyRef<PrivateKey> = ctor.call1(py, args).unwrap().extract(py).unwrap();
+            assert_eq!(unpickled.to_bytes(py).unwrap().as_bytes(), bytes.as_bytes());
+
+            let fragment = sk.split(2, 3).unwrap().into_iter().next().unwrap();
+            let fragment_bytes = fragment.to_bytes(py).unwrap();
+            let decoded_fragment =
+                PrivateKey::from_bytes(py.get_type::<PrivateKey>(), fragment_bytes).unwrap();
+            assert!(decoded_fragment.is_fragment().unwrap());
+        });
+    }
+
+    #[test]
+    fn private_key_from_bytes_rejects_an_unrecognized_tag() {
+        Python::with_gil(|py| {
+            let mut bytes = [0u8; 33];
+            bytes[0] = 2;
+            let bytes = PyBytes::new(py, &bytes);
+            assert!(PrivateKey::from_bytes(py.get_type::<PrivateKey>(), bytes).is_err());
+        });
+    }
+
+    #[test]
+    fn public_key_bytes_round_trips_through_bytes_and_pickle() {
+        Python::with_gil(|py| {
+            let sk = PrivateKey::random(py.get_type::<PrivateKey>()).unwrap();
+            let pk = sk.public_key().unwrap();
+            let bytes = pk.to_bytes(py).unwrap();
+
+            let lazy = PublicKeyBytes::new(bytes).unwrap();
+            assert_eq!(lazy.to_bytes(py).unwrap().as_bytes(), bytes.as_bytes());
+            assert_eq!(
+                lazy.checked().unwrap().to_bytes(py).unwrap().as_bytes(),
+                bytes.as_bytes()
+            );
+
+            let (ctor, args) = lazy.__reduce__(py).unwrap();
+            let unpickled: PyRef<PublicKeyBytes> = ctor.call1(py, args).unwrap().extract(py).unwrap();
+            assert_eq!(unpickled.to_bytes(py).unwrap().as_bytes(), bytes.as_bytes());
+        });
+    }
+
+    #[test]
+    fn public_key_bytes_checked_rejects_a_non_canonical_point() {
+        Python::with_gil(|py| {
+            // `new` is validation-free by design, so the malformed point must
+            // surface here instead of panicking through `PublicKeyStub`.
+            let mut bytes = [0u8; 48];
+            bytes[0] = 0xE0;
+            let lazy = PublicKeyBytes::new(PyBytes::new(py, &bytes)).unwrap();
+            assert!(lazy.checked().is_err());
+        });
+    }
 }
@@ -0,0 +1,23 @@
+use pyo3::prelude::*;
+
+pub mod bls;
+pub mod dkg;
+pub mod errors;
+pub mod hash_to_curve;
+pub mod keys;
+
+#[pymodule]
+fn pynubls(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<keys::PrivateKey>()?;
+    m.add_class::<keys::PublicKey>()?;
+    m.add_class::<keys::PublicKeyBytes>()?;
+    m.add_class::<bls::Signature>()?;
+    m.add_class::<dkg::DkgParticipant>()?;
+    m.add("InvalidSignature", _py.get_type::<bls::InvalidSignature>())?;
+    m.add("DkgComplaint", _py.get_type::<dkg::DkgComplaint>())?;
+    m.add(
+        "DeserializationError",
+        _py.get_type::<errors::DeserializationError>(),
+    )?;
+    Ok(())
+}